@@ -2,10 +2,348 @@
 //!
 //! This is an implementation of the human-oriented base-32 encoding called
 //! [z-base32](https://philzimmermann.com/docs/human-oriented-base-32-encoding.txt).
+//!
+//! The core bit-packing is shared by the [`Alphabet`] enum, which also offers
+//! the [RFC 4648][rfc] and [Crockford][crockford] base32 variants. The free
+//! [`encode`] / [`decode`] functions are thin wrappers over
+//! [`Alphabet::ZBase32`].
+//!
+//! The slice-writing [`encode_into`] / [`decode_into`] functions together with
+//! the [`encoded_len`] / [`decoded_len`] size helpers perform no heap
+//! allocation and are available without the `alloc` feature. The convenience
+//! functions returning `String`/`Vec` require the (default) `alloc` feature.
+//!
+//! [rfc]: https://datatracker.ietf.org/doc/html/rfc4648#section-6
+//! [crockford]: https://www.crockford.com/base32.html
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec, vec::Vec};
 
 /// Alphabet used by zbase32
 pub const ALPHABET: &[u8; 32] = b"ybndrfg8ejkmcpqxot1uwisza345h769";
 
+/// RFC 4648 base32 alphabet.
+const RFC4648: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// Crockford base32 alphabet.
+const CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Padding character used by the RFC 4648 variant.
+const PAD: u8 = b'=';
+
+/// Sentinel stored in the inverse tables for bytes that are not part of the
+/// alphabet.
+const INVALID: u8 = 0xFF;
+
+/// Build the inverse of an encode table: map an ASCII byte to its 5-bit value,
+/// or [`INVALID`] for characters outside the alphabet. Both the lower- and
+/// uppercase forms of any letter are accepted so that decoding is
+/// case-insensitive. When `crockford` is set, `I`/`L` alias `1` and `O`
+/// aliases `0`, per the Crockford spec.
+const fn build_inverse(table: &[u8; 32], crockford: bool) -> [u8; 256] {
+    let mut inv = [INVALID; 256];
+    let mut i = 0;
+    while i < 32 {
+        let c = table[i];
+        inv[c as usize] = i as u8;
+        if c >= b'a' && c <= b'z' {
+            inv[(c - 32) as usize] = i as u8;
+        } else if c >= b'A' && c <= b'Z' {
+            inv[(c + 32) as usize] = i as u8;
+        }
+        i += 1;
+    }
+    if crockford {
+        inv[b'I' as usize] = inv[b'1' as usize];
+        inv[b'i' as usize] = inv[b'1' as usize];
+        inv[b'L' as usize] = inv[b'1' as usize];
+        inv[b'l' as usize] = inv[b'1' as usize];
+        inv[b'O' as usize] = inv[b'0' as usize];
+        inv[b'o' as usize] = inv[b'0' as usize];
+    }
+    inv
+}
+
+const ZBASE32_INVERSE: [u8; 256] = build_inverse(ALPHABET, false);
+const RFC4648_INVERSE: [u8; 256] = build_inverse(RFC4648, false);
+const CROCKFORD_INVERSE: [u8; 256] = build_inverse(CROCKFORD, true);
+
+/// Error returned when an output buffer is too small to hold the result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError;
+
+impl core::fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("output buffer too small")
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::error::Error for CapacityError {}
+
+/// Error returned when decoding fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// Input contained a byte that is not part of the alphabet.
+    InvalidCharacter(u8),
+    /// The trailing partial group carried non-zero bits. A well-formed encoder
+    /// always zero-pads the final group, so non-zero padding indicates a
+    /// malformed or truncated input.
+    TrailingBits,
+    /// The caller-supplied output buffer was too small.
+    InsufficientCapacity,
+}
+
+impl core::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DecodeError::InvalidCharacter(c) => {
+                write!(f, "invalid base32 character: {:#04x}", c)
+            }
+            DecodeError::TrailingBits => f.write_str("non-zero trailing bits in final group"),
+            DecodeError::InsufficientCapacity => f.write_str("output buffer too small"),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::error::Error for DecodeError {}
+
+/// Number of zbase32 characters needed to encode N `bits`.
+pub const fn encoded_len(bits: usize) -> usize {
+    if bits % 5 == 0 {
+        bits / 5
+    } else {
+        bits / 5 + 1
+    }
+}
+
+/// Number of whole bytes produced by decoding `chars` zbase32 characters.
+pub const fn decoded_len(chars: usize) -> usize {
+    chars * 5 / 8
+}
+
+/// A base32 alphabet.
+///
+/// All three variants share the same bit-packing; they differ only in the
+/// 32-byte character set, in decode leniency, and — for [`Rfc4648`] — in
+/// `=` padding to whole 8-character blocks.
+///
+/// [`Rfc4648`]: Alphabet::Rfc4648
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Alphabet {
+    /// The human-oriented z-base-32 alphabet (this crate's default).
+    ZBase32,
+    /// The RFC 4648 alphabet, with `=` padding up to 8-character blocks.
+    Rfc4648,
+    /// The Crockford alphabet: `I`/`L` decode as `1` and `O` as `0`.
+    Crockford,
+}
+
+impl Alphabet {
+    /// The 32-byte encode table for this alphabet.
+    const fn table(self) -> &'static [u8; 32] {
+        match self {
+            Alphabet::ZBase32 => ALPHABET,
+            Alphabet::Rfc4648 => RFC4648,
+            Alphabet::Crockford => CROCKFORD,
+        }
+    }
+
+    /// The inverse (decode) table for this alphabet.
+    fn inverse(self) -> &'static [u8; 256] {
+        match self {
+            Alphabet::ZBase32 => &ZBASE32_INVERSE,
+            Alphabet::Rfc4648 => &RFC4648_INVERSE,
+            Alphabet::Crockford => &CROCKFORD_INVERSE,
+        }
+    }
+
+    /// Number of characters (including any `=` padding) needed to encode N
+    /// `bits` with this alphabet.
+    pub const fn encoded_len(self, bits: usize) -> usize {
+        let chars = encoded_len(bits);
+        match self {
+            Alphabet::Rfc4648 => (chars + 7) / 8 * 8,
+            _ => chars,
+        }
+    }
+
+    /// Encode the first N `bits` of `buf` into `out`, returning the number of
+    /// characters written.
+    ///
+    /// Performs no allocation. Size `out` with [`Alphabet::encoded_len`]; a
+    /// shorter buffer yields [`CapacityError`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buf` is shorter than N `bits`.
+    pub fn encode_into(self, buf: &[u8], bits: usize, out: &mut [u8]) -> Result<usize, CapacityError> {
+        let needed = self.encoded_len(bits);
+        if out.len() < needed {
+            return Err(CapacityError);
+        }
+
+        let table = self.table();
+        let mut n = 0;
+        for p in (0..bits).step_by(5) {
+            let i = p >> 3;
+            let j = p & 7;
+            let mut idx = if j <= 3 {
+                (buf[i] >> (3 - j)) & 0b11111
+            } else {
+                let of = j - 3;
+                let h = (buf[i] << of) & 0b11111;
+                let l = if i >= buf.len() - 1 {
+                    0
+                } else {
+                    buf[i + 1] >> (8 - of)
+                };
+                h | l
+            };
+            // Zero the bits of the final group that lie past N `bits`.
+            if p + 5 > bits {
+                idx &= 0b11111 << (p + 5 - bits);
+            }
+            out[n] = table[idx as usize];
+            n += 1;
+        }
+
+        // RFC 4648 pads the final group out to a whole 8-character block.
+        if let Alphabet::Rfc4648 = self {
+            while n < needed {
+                out[n] = PAD;
+                n += 1;
+            }
+        }
+
+        Ok(n)
+    }
+
+    /// Decode the first N `bits` of `data` into `out`, returning the number of
+    /// bytes written.
+    ///
+    /// Performs no allocation. Accepts both lower- and uppercase input, skips
+    /// any trailing `=` padding, and requires the leftover bits of the final
+    /// group to be zero.
+    pub fn decode_into(self, data: &[u8], bits: usize, out: &mut [u8]) -> Result<usize, DecodeError> {
+        let needed = bits / 8;
+        if out.len() < needed {
+            return Err(DecodeError::InsufficientCapacity);
+        }
+
+        let inverse = self.inverse();
+        let mut buffer: u16 = 0;
+        let mut have = 0usize;
+        let mut n = 0usize;
+        // The trailing-bits invariant only holds for the genuine final group.
+        // When `data` encodes more than N bits (a subset decode), the bits past
+        // the requested prefix belong to later bytes, not to zero padding.
+        let mut final_group = true;
+
+        for &c in data {
+            if c == PAD {
+                continue;
+            }
+
+            let value = inverse[c as usize];
+            if value == INVALID {
+                return Err(DecodeError::InvalidCharacter(c));
+            }
+
+            if n == needed {
+                // All requested bytes are produced; keep validating the
+                // remaining characters but stop feeding the bit buffer.
+                final_group = false;
+                continue;
+            }
+
+            buffer = (buffer << 5) | value as u16;
+            have += 5;
+
+            if have >= 8 {
+                have -= 8;
+                out[n] = (buffer >> have) as u8;
+                n += 1;
+                buffer &= (1 << have) - 1;
+            }
+        }
+
+        // A proper encoder zero-pads the final group; anything else is malformed.
+        if final_group && buffer != 0 {
+            return Err(DecodeError::TrailingBits);
+        }
+
+        Ok(n)
+    }
+
+    /// Encode the first N `bits` of `buf` with this alphabet.
+    #[cfg(feature = "alloc")]
+    pub fn encode(self, buf: &[u8], bits: usize) -> String {
+        let mut s = vec![0u8; self.encoded_len(bits)];
+        let n = self
+            .encode_into(buf, bits, &mut s)
+            .expect("buffer sized by encoded_len");
+        s.truncate(n);
+        unsafe { String::from_utf8_unchecked(s) }
+    }
+
+    /// Decode the first N `bits` of a string with this alphabet.
+    #[cfg(feature = "alloc")]
+    pub fn decode(self, data: &[u8], bits: usize) -> Result<Vec<u8>, DecodeError> {
+        let mut out = vec![0u8; bits / 8];
+        let n = self.decode_into(data, bits, &mut out)?;
+        out.truncate(n);
+        Ok(out)
+    }
+}
+
+/// Encode the first N `bits` of `buf` into `out`, returning the number of
+/// characters written.
+///
+/// Performs no allocation. Size `out` with [`encoded_len`]; a shorter buffer
+/// yields [`CapacityError`].
+///
+/// # Panics
+///
+/// Panics if `buf` is shorter than N `bits`.
+///
+/// # Examples
+///
+/// ```
+/// use z32;
+///
+/// let mut out = [0u8; z32::encoded_len(64)];
+/// let n = z32::encode_into(b"The quic", 64, &mut out).unwrap();
+/// assert_eq!(&out[..n], b"ktwgkedtqiwsg");
+/// ```
+pub fn encode_into(buf: &[u8], bits: usize, out: &mut [u8]) -> Result<usize, CapacityError> {
+    Alphabet::ZBase32.encode_into(buf, bits, out)
+}
+
+/// Decode the first N `bits` of `data` into `out`, returning the number of
+/// bytes written.
+///
+/// Performs no allocation. Size `out` with [`decoded_len`] (or `bits / 8`); a
+/// shorter buffer yields [`DecodeError::InsufficientCapacity`].
+///
+/// # Examples
+///
+/// ```
+/// use z32;
+///
+/// let mut out = [0u8; 8];
+/// let n = z32::decode_into(b"ktwgkedtqiwsg", 64, &mut out).unwrap();
+/// assert_eq!(&out[..n], b"The quic");
+/// ```
+pub fn decode_into(data: &[u8], bits: usize, out: &mut [u8]) -> Result<usize, DecodeError> {
+    Alphabet::ZBase32.decode_into(data, bits, out)
+}
+
 /// Encode first N `bits` with zbase32.
 ///
 /// # Panics
@@ -21,33 +359,9 @@ pub const ALPHABET: &[u8; 32] = b"ybndrfg8ejkmcpqxot1uwisza345h769";
 /// assert_eq!(z32::encode(data.as_bytes(), 64), "ktwgkedtqiwsg");
 /// ```
 ///
+#[cfg(feature = "alloc")]
 pub fn encode(buf: &[u8], bits: usize) -> String {
-    let capacity = if bits % 5 == 0 {
-        bits / 5
-    } else {
-        bits / 5 + 1
-    } as usize;
-
-    let mut s = Vec::with_capacity(capacity);
-
-    for p in (0..bits).step_by(5) {
-        let i = p >> 3;
-        let j = p & 7;
-        if j <= 3 {
-            s.push(ALPHABET[((buf[i] >> (3 - j)) & 0b11111) as usize]);
-        } else {
-            let of = j - 3;
-            let h = (buf[i] << of) & 0b11111;
-            let l = if i >= buf.len() - 1 {
-                0
-            } else {
-                buf[i + 1] >> (8 - of)
-            };
-            s.push(ALPHABET[(h | l) as usize]);
-        }
-    }
-
-    unsafe { String::from_utf8_unchecked(s) }
+    Alphabet::ZBase32.encode(buf, bits)
 }
 
 /// Encode full bytes using zbase32.
@@ -63,10 +377,200 @@ pub fn encode(buf: &[u8], bits: usize) -> String {
 /// assert_eq!(z32::encode_full_bytes(data.as_bytes()),
 ///            "ktwgkedtqiwsg43ycj3g675qrbug66bypj4s4hdurbzzc3m1rb4go3jyptozw6jyctzsqmty6nx3dyy");
 /// ```
+#[cfg(feature = "alloc")]
 pub fn encode_full_bytes(buf: &[u8]) -> String {
     encode(buf, buf.len() * 8)
 }
 
+/// Decode the first N `bits` of a zbase32 string.
+///
+/// Accepts both lower- and uppercase input. Returns `bits / 8` whole bytes, or
+/// fewer if `data` holds fewer bits than that; the leftover bits of the final group must be zero, as produced by a
+/// well-formed encoder, otherwise [`DecodeError::TrailingBits`] is returned.
+///
+/// # Examples
+///
+/// ```
+/// use z32;
+///
+/// let data = "The quick brown fox jumps over the lazy dog. ðŸ‘€";
+/// let encoded = z32::encode(data.as_bytes(), 64);
+/// assert_eq!(z32::decode(encoded.as_bytes(), 64).unwrap(), &data.as_bytes()[..8]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode(data: &[u8], bits: usize) -> Result<Vec<u8>, DecodeError> {
+    Alphabet::ZBase32.decode(data, bits)
+}
+
+/// Decode full bytes from a zbase32 string.
+///
+/// Just like `decode` but infers `bits` as `data.len() * 5` rounded down to
+/// whole bytes.
+///
+/// # Examples
+///
+/// ```
+/// use z32;
+///
+/// let data = "The quick brown fox jumps over the lazy dog. ðŸ‘€";
+/// let encoded = z32::encode_full_bytes(data.as_bytes());
+/// assert_eq!(z32::decode_full_bytes(encoded.as_bytes()).unwrap(), data.as_bytes());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_full_bytes(data: &[u8]) -> Result<Vec<u8>, DecodeError> {
+    let bits = decoded_len(data.len()) * 8;
+    decode(data, bits)
+}
+
+/// Incremental zbase32-family encoder for chunked or streaming input.
+///
+/// Holds a small bit residue (at most 4 leftover bits) between [`update`]
+/// calls, so arbitrarily large data can be encoded in constant memory without
+/// a contiguous input slice. Each emitted character is handed to the `sink`
+/// closure.
+///
+/// [`update`]: Encoder::update
+///
+/// # Examples
+///
+/// ```
+/// use z32::{Alphabet, Encoder};
+///
+/// let mut out = Vec::new();
+/// let mut enc = Encoder::new(Alphabet::ZBase32);
+/// enc.update(b"The ", |c| out.push(c));
+/// enc.update(b"quic", |c| out.push(c));
+/// enc.finalize(|c| out.push(c));
+/// assert_eq!(out, b"ktwgkedtqiwsg");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Encoder {
+    table: &'static [u8; 32],
+    acc: u16,
+    bits: usize,
+}
+
+impl Encoder {
+    /// Create an encoder for the given alphabet.
+    pub fn new(alphabet: Alphabet) -> Self {
+        Encoder {
+            table: alphabet.table(),
+            acc: 0,
+            bits: 0,
+        }
+    }
+
+    /// Feed a chunk of input, emitting a character through `sink` for every
+    /// complete 5-bit group.
+    pub fn update(&mut self, bytes: &[u8], mut sink: impl FnMut(u8)) {
+        for &b in bytes {
+            self.acc = (self.acc << 8) | b as u16;
+            self.bits += 8;
+            while self.bits >= 5 {
+                self.bits -= 5;
+                let idx = (self.acc >> self.bits) & 0b11111;
+                sink(self.table[idx as usize]);
+            }
+            self.acc &= (1 << self.bits) - 1;
+        }
+    }
+
+    /// Flush the trailing `<5` residual bits, followed by zero bits to fill the
+    /// group, emitting the final character if any bits remain.
+    pub fn finalize(self, mut sink: impl FnMut(u8)) {
+        if self.bits > 0 {
+            let idx = (self.acc << (5 - self.bits)) & 0b11111;
+            sink(self.table[idx as usize]);
+        }
+    }
+}
+
+impl Default for Encoder {
+    fn default() -> Self {
+        Encoder::new(Alphabet::ZBase32)
+    }
+}
+
+/// Incremental zbase32-family decoder for chunked or streaming input.
+///
+/// The mirror of [`Encoder`]: holds a running bit residue between [`update`]
+/// calls and emits a byte through the `sink` closure for every complete 8-bit
+/// group. Call [`finalize`] to validate that the trailing bits are zero.
+///
+/// [`update`]: Decoder::update
+/// [`finalize`]: Decoder::finalize
+///
+/// # Examples
+///
+/// ```
+/// use z32::{Alphabet, Decoder};
+///
+/// let mut out = Vec::new();
+/// let mut dec = Decoder::new(Alphabet::ZBase32);
+/// dec.update(b"ktwgke", |b| out.push(b)).unwrap();
+/// dec.update(b"dtqiwsg", |b| out.push(b)).unwrap();
+/// dec.finalize().unwrap();
+/// assert_eq!(out, b"The quic");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Decoder {
+    inverse: &'static [u8; 256],
+    acc: u16,
+    bits: usize,
+}
+
+impl Decoder {
+    /// Create a decoder for the given alphabet.
+    pub fn new(alphabet: Alphabet) -> Self {
+        Decoder {
+            inverse: alphabet.inverse(),
+            acc: 0,
+            bits: 0,
+        }
+    }
+
+    /// Feed a chunk of input, emitting a byte through `sink` for every
+    /// complete 8-bit group. Trailing `=` padding is ignored.
+    pub fn update(&mut self, chars: &[u8], mut sink: impl FnMut(u8)) -> Result<(), DecodeError> {
+        for &c in chars {
+            if c == PAD {
+                continue;
+            }
+
+            let value = self.inverse[c as usize];
+            if value == INVALID {
+                return Err(DecodeError::InvalidCharacter(c));
+            }
+
+            self.acc = (self.acc << 5) | value as u16;
+            self.bits += 5;
+
+            if self.bits >= 8 {
+                self.bits -= 8;
+                sink((self.acc >> self.bits) as u8);
+                self.acc &= (1 << self.bits) - 1;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Verify that the residual bits are zero, as produced by a well-formed
+    /// encoder.
+    pub fn finalize(self) -> Result<(), DecodeError> {
+        if self.acc != 0 {
+            return Err(DecodeError::TrailingBits);
+        }
+        Ok(())
+    }
+}
+
+impl Default for Decoder {
+    fn default() -> Self {
+        Decoder::new(Alphabet::ZBase32)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -83,19 +587,134 @@ mod test {
             );
         }
 
-        // let decoded = decode_full_bytes_str(&encoded);
-        // println!("Decoded: {:?}", decoded);
-        //
-
         {
             let encoded = encode(input.as_bytes(), 64);
             assert_eq!(encoded, "ktwgkedtqiwsg");
         }
     }
 
-    // #[test]
-    // fn random() {
-    //     let mut rng = rand::thread_rng();
-    //     let random_bytes: [u8; 20] = rng.gen();
-    // }
+    #[test]
+    fn round_trip() {
+        let input = "The quick brown fox jumps over the lazy dog. ðŸ‘€";
+
+        {
+            let bits = input.len() * 8;
+            let encoded = encode_full_bytes(input.as_bytes());
+            assert_eq!(decode(encoded.as_bytes(), bits).unwrap(), input.as_bytes());
+            assert_eq!(
+                decode_full_bytes(encoded.as_bytes()).unwrap(),
+                input.as_bytes()
+            );
+        }
+
+        {
+            let encoded = encode(input.as_bytes(), 64);
+            assert_eq!(decode(encoded.as_bytes(), 64).unwrap(), &input.as_bytes()[..8]);
+        }
+
+        {
+            // Decoding a prefix of a longer encoding must not trip the
+            // trailing-bits check on bits that belong to later bytes.
+            let encoded = encode(input.as_bytes(), 64);
+            assert_eq!(decode(encoded.as_bytes(), 8).unwrap(), &input.as_bytes()[..1]);
+        }
+    }
+
+    #[test]
+    fn case_insensitive() {
+        let encoded = encode_full_bytes(b"hello");
+        assert_eq!(
+            decode_full_bytes(encoded.to_uppercase().as_bytes()).unwrap(),
+            decode_full_bytes(encoded.as_bytes()).unwrap()
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_character() {
+        assert_eq!(
+            decode(b"ktwg!", 16),
+            Err(DecodeError::InvalidCharacter(b'!'))
+        );
+    }
+
+    #[test]
+    fn into_buffers_round_trip() {
+        let input = b"The quic";
+
+        let mut encoded = [0u8; encoded_len(64)];
+        let n = encode_into(input, 64, &mut encoded).unwrap();
+        assert_eq!(&encoded[..n], b"ktwgkedtqiwsg");
+
+        let mut decoded = [0u8; 8];
+        let m = decode_into(&encoded[..n], 64, &mut decoded).unwrap();
+        assert_eq!(&decoded[..m], input);
+    }
+
+    #[test]
+    fn into_reports_capacity() {
+        let mut small = [0u8; 4];
+        assert_eq!(encode_into(b"The quic", 64, &mut small), Err(CapacityError));
+        assert_eq!(
+            decode_into(b"ktwgkedtqiwsg", 64, &mut small),
+            Err(DecodeError::InsufficientCapacity)
+        );
+    }
+
+    #[test]
+    fn alphabets_round_trip() {
+        let input = b"foobar";
+        let bits = input.len() * 8;
+
+        for alphabet in [Alphabet::ZBase32, Alphabet::Rfc4648, Alphabet::Crockford] {
+            let encoded = alphabet.encode(input, bits);
+            assert_eq!(alphabet.decode(encoded.as_bytes(), bits).unwrap(), input);
+        }
+    }
+
+    #[test]
+    fn rfc4648_vectors() {
+        // From RFC 4648 §10.
+        assert_eq!(Alphabet::Rfc4648.encode(b"foobar", 48), "MZXW6YTBOI======");
+        assert_eq!(
+            Alphabet::Rfc4648.decode(b"MZXW6YTBOI======", 48).unwrap(),
+            b"foobar"
+        );
+    }
+
+    #[test]
+    fn crockford_decode_aliases() {
+        // I/L alias 1 and O aliases 0, case-insensitively.
+        let canonical = Alphabet::Crockford.decode(b"D1N0", 16).unwrap();
+        assert_eq!(Alphabet::Crockford.decode(b"dIno", 16).unwrap(), canonical);
+    }
+
+    #[test]
+    fn streaming_matches_one_shot() {
+        let input = "The quick brown fox jumps over the lazy dog. ðŸ‘€".as_bytes();
+
+        let mut streamed = Vec::new();
+        let mut enc = Encoder::default();
+        // Feed in awkwardly sized chunks to exercise the residue buffer.
+        for chunk in input.chunks(3) {
+            enc.update(chunk, |c| streamed.push(c));
+        }
+        enc.finalize(|c| streamed.push(c));
+
+        assert_eq!(streamed, encode_full_bytes(input).as_bytes());
+    }
+
+    #[test]
+    fn streaming_round_trip() {
+        let input = "The quick brown fox jumps over the lazy dog. ðŸ‘€".as_bytes();
+        let encoded = encode_full_bytes(input);
+
+        let mut decoded = Vec::new();
+        let mut dec = Decoder::default();
+        for chunk in encoded.as_bytes().chunks(5) {
+            dec.update(chunk, |b| decoded.push(b)).unwrap();
+        }
+        dec.finalize().unwrap();
+
+        assert_eq!(decoded, input);
+    }
 }